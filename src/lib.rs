@@ -1,3 +1,5 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
 use core::ffi::CStr;
 use core::fmt::Formatter;
 
@@ -19,6 +21,7 @@ impl From<core::num::ParseIntError> for Error {
 
 // When `error_in_core` lands this can be made `core::error::Error`
 //  see issue #103765 https://github.com/rust-lang/rust/issues/103765
+#[cfg(feature = "std")]
 impl std::error::Error for Error {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
@@ -39,10 +42,145 @@ impl core::fmt::Display for Error {
     }
 }
 
+/// Thin backend for the handful of syscalls this crate makes directly
+/// (`open`/`read`/`close`/`mmap`/`munmap`). By default these go through libc,
+/// same as ever. With the `raw-syscalls` feature, they go through a hand-rolled
+/// `syscall` instruction instead, so that once `remove_timer_mappings` has
+/// unmapped the vDSO, reading `/proc/self/maps` and unmapping or replacing
+/// mappings never re-enter glibc and accidentally hit a now-dangling vDSO
+/// trampoline on the way back out. `disable_tsc` and `block_timing_syscalls`
+/// still call `prctl` through libc with either backend. The hand-rolled
+/// backend is x86_64-only; other arches fall back to the libc backend even
+/// with `raw-syscalls` enabled.
+#[cfg(target_os = "linux")]
+mod sys {
+    #[cfg(not(all(feature = "raw-syscalls", target_arch = "x86_64")))]
+    pub use libc_backend::*;
+    #[cfg(all(feature = "raw-syscalls", target_arch = "x86_64"))]
+    pub use raw_backend::*;
+
+    #[cfg(not(all(feature = "raw-syscalls", target_arch = "x86_64")))]
+    mod libc_backend {
+        use libc::*;
+
+        pub unsafe fn open(path: *const c_char, flags: c_int) -> c_int {
+            libc::open(path, flags)
+        }
+
+        pub unsafe fn read(fd: c_int, buf: *mut c_void, count: size_t) -> ssize_t {
+            libc::read(fd, buf, count)
+        }
+
+        pub unsafe fn close(fd: c_int) -> c_int {
+            libc::close(fd)
+        }
+
+        pub unsafe fn mmap(
+            addr: *mut c_void,
+            len: size_t,
+            prot: c_int,
+            flags: c_int,
+            fd: c_int,
+            offset: off_t,
+        ) -> *mut c_void {
+            libc::mmap(addr, len, prot, flags, fd, offset)
+        }
+
+        pub unsafe fn munmap(addr: *mut c_void, len: size_t) -> c_int {
+            libc::munmap(addr, len)
+        }
+    }
+
+    #[cfg(all(feature = "raw-syscalls", target_arch = "x86_64"))]
+    mod raw_backend {
+        use core::arch::asm;
+        use libc::{c_char, c_int, c_void, off_t, size_t, ssize_t};
+
+        const SYS_READ: i64 = 0;
+        const SYS_OPEN: i64 = 2;
+        const SYS_CLOSE: i64 = 3;
+        const SYS_MMAP: i64 = 9;
+        const SYS_MUNMAP: i64 = 11;
+
+        #[inline]
+        unsafe fn syscall3(nr: i64, a1: i64, a2: i64, a3: i64) -> i64 {
+            let ret: i64;
+            asm!(
+                "syscall",
+                inlateout("rax") nr => ret,
+                in("rdi") a1,
+                in("rsi") a2,
+                in("rdx") a3,
+                lateout("rcx") _,
+                lateout("r11") _,
+                options(nostack),
+            );
+            ret
+        }
+
+        #[inline]
+        #[allow(clippy::too_many_arguments)]
+        unsafe fn syscall6(nr: i64, a1: i64, a2: i64, a3: i64, a4: i64, a5: i64, a6: i64) -> i64 {
+            let ret: i64;
+            asm!(
+                "syscall",
+                inlateout("rax") nr => ret,
+                in("rdi") a1,
+                in("rsi") a2,
+                in("rdx") a3,
+                in("r10") a4,
+                in("r8") a5,
+                in("r9") a6,
+                lateout("rcx") _,
+                lateout("r11") _,
+                options(nostack),
+            );
+            ret
+        }
+
+        // These mirror the libc backend's signatures and error conventions (negative
+        // return on failure) so call sites don't need to care which backend is active.
+        pub unsafe fn open(path: *const c_char, flags: c_int) -> c_int {
+            syscall3(SYS_OPEN, path as i64, flags as i64, 0) as c_int
+        }
+
+        pub unsafe fn read(fd: c_int, buf: *mut c_void, count: size_t) -> ssize_t {
+            syscall3(SYS_READ, fd as i64, buf as i64, count as i64) as ssize_t
+        }
+
+        pub unsafe fn close(fd: c_int) -> c_int {
+            syscall3(SYS_CLOSE, fd as i64, 0, 0) as c_int
+        }
+
+        pub unsafe fn mmap(
+            addr: *mut c_void,
+            len: size_t,
+            prot: c_int,
+            flags: c_int,
+            fd: c_int,
+            offset: off_t,
+        ) -> *mut c_void {
+            syscall6(
+                SYS_MMAP,
+                addr as i64,
+                len as i64,
+                prot as i64,
+                flags as i64,
+                fd as i64,
+                offset,
+            ) as *mut c_void
+        }
+
+        pub unsafe fn munmap(addr: *mut c_void, len: size_t) -> c_int {
+            syscall3(SYS_MUNMAP, addr as i64, len as i64, 0) as c_int
+        }
+    }
+}
+
 // Function to unmap a memory region
 #[cfg(target_os = "linux")]
 unsafe fn unmap_region(address: *mut c_void, size: size_t) -> Result<(), Error> {
-    let errno = munmap(address, size);
+    let errno = sys::munmap(address, size);
     if errno == 0 {
         Ok(())
     } else {
@@ -77,24 +215,52 @@ pub fn test_clock() -> ! {
     panic!("test_clock is only available on linux");
 }
 
+/// A single record parsed out of `/proc/self/maps`, describing one mapping
+/// in this process's address space, e.g. the vDSO or vvar mapping.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone)]
+struct MappingRecord {
+    start: *mut libc::c_void,
+    end: *mut libc::c_void,
+    /// The four permission characters verbatim, e.g. `r-xp`. Not read anywhere
+    /// yet, but kept on the record since it's part of what `/proc/self/maps`
+    /// actually reports and future callers (e.g. a sanity check that vdso/vvar
+    /// are still `r-xp`/`r--p` before trusting them) will want it.
+    #[allow(dead_code)]
+    perms: [u8; 4],
+    /// The trailing pathname or pseudo-name field (e.g. `[vdso]`, `/lib/libc.so`),
+    /// if the line had one.
+    name: Option<std::string::String>,
+}
+
+#[cfg(feature = "std")]
+impl MappingRecord {
+    fn size(&self) -> libc::size_t {
+        self.end as libc::size_t - self.start as libc::size_t
+    }
+
+    fn name_is(&self, tag: &str) -> bool {
+        self.name.as_deref() == Some(tag)
+    }
+}
+
 // This is used internally, exclusively, so I don't feel the need to refactor the return type
 // let path = unsafe {
 // // SAFETY: This is a valid, static C string
 // CStr::from_bytes_until_nul(b"/proc/self/maps\x00").unwrap_unchecked()
 // };
-#[cfg(target_os = "linux")]
-fn find_mapping_addresses() -> Result<
-    (
-        Option<(*mut libc::c_void, libc::size_t)>,
-        Option<(*mut libc::c_void, libc::size_t)>,
-    ),
-    Error,
-> {
+/// Parses every record out of `/proc/self/maps`, so callers can reason about
+/// the whole address space rather than just the two mappings this crate cares
+/// about by default. Collecting a `Vec` of records needs an allocator, so this
+/// path is only available with the `std` feature; the `no_std` build looks up
+/// the vdso/vvar mappings directly in [`find_mapping_addresses`] instead.
+#[cfg(all(target_os = "linux", feature = "std"))]
+fn find_all_mappings() -> Result<std::vec::Vec<MappingRecord>, Error> {
     let path = unsafe {
         // SAFETY: This is a valid, static C string
         CStr::from_bytes_until_nul(b"/proc/self/maps\x00").unwrap_unchecked()
     };
-    let fd = unsafe { open(path.as_ptr(), O_RDONLY) };
+    let fd = unsafe { sys::open(path.as_ptr(), O_RDONLY) };
     if fd < 0 {
         return Err(Error::IoError("open", fd));
     }
@@ -106,22 +272,98 @@ fn find_mapping_addresses() -> Result<
     // but we can make it larger in case there's every something odd about it
     let mut line = [0u8; 1024];
     let mut line_idx = 0;
+    let mut mappings = std::vec::Vec::new();
+
+    loop {
+        let bytes_read = unsafe {
+            sys::read(fd, buffer.as_mut_ptr() as *mut libc::c_void, buffer.len())
+        };
+        if bytes_read < 0 {
+            unsafe { sys::close(fd) };
+            return Err(Error::IoError("read", bytes_read as libc::c_int));
+        }
+        if bytes_read == 0 {
+            break; // EOF
+        }
+
+        for &byte in &buffer[..bytes_read as usize] {
+            if byte == b'\n' {
+                // Only the bytes actually written for this record are valid; anything
+                // past `line_idx` is stale from a previous, longer line.
+                mappings.push(parse_mapping_line(&line[..line_idx])?);
+                line_idx = 0; // Reset for the next line
+            } else {
+                if line_idx < line.len() {
+                    line[line_idx] = byte;
+                    line_idx += 1;
+                }
+            }
+        }
+    }
+
+    unsafe { sys::close(fd) };
+    Ok(mappings)
+}
+
+/// A mapping's `(start address, size)`, if it's present at all.
+#[cfg(target_os = "linux")]
+type MappingPair = Option<(*mut libc::c_void, libc::size_t)>;
+
+#[cfg(all(target_os = "linux", feature = "std"))]
+fn find_mapping_addresses() -> Result<(MappingPair, MappingPair), Error> {
+    let mappings = find_all_mappings()?;
+    let vvar = mappings
+        .iter()
+        .find(|m| m.name_is("[vvar]"))
+        .map(|m| (m.start, m.size()));
+    let vdso = mappings
+        .iter()
+        .find(|m| m.name_is("[vdso]"))
+        .map(|m| (m.start, m.size()));
+    Ok((vvar, vdso))
+}
+
+/// Same lookup as the `std` version above, but without collecting every
+/// mapping into a `Vec`: it scans each line for the `[vdso]`/`[vvar]` tag and
+/// parses addresses only on a match, so it needs no allocator.
+#[cfg(all(target_os = "linux", not(feature = "std")))]
+fn find_mapping_addresses() -> Result<(MappingPair, MappingPair), Error> {
+    let path = unsafe {
+        // SAFETY: This is a valid, static C string
+        CStr::from_bytes_until_nul(b"/proc/self/maps\x00").unwrap_unchecked()
+    };
+    let fd = unsafe { sys::open(path.as_ptr(), O_RDONLY) };
+    if fd < 0 {
+        return Err(Error::IoError("open", fd));
+    }
+
+    let mut buffer = [0u8; 4096];
+    let mut line = [0u8; 1024];
+    let mut line_idx = 0;
     let mut vvar = None;
     let mut vdso = None;
 
     loop {
-        let bytes_read =
-            unsafe { read(fd, buffer.as_mut_ptr() as *mut libc::c_void, buffer.len()) };
-        if bytes_read <= 0 {
-            break; // EOF or error
+        let bytes_read = unsafe {
+            sys::read(fd, buffer.as_mut_ptr() as *mut libc::c_void, buffer.len())
+        };
+        if bytes_read < 0 {
+            unsafe { sys::close(fd) };
+            return Err(Error::IoError("read", bytes_read as libc::c_int));
+        }
+        if bytes_read == 0 {
+            break; // EOF
         }
 
         for &byte in &buffer[..bytes_read as usize] {
             if byte == b'\n' {
-                if line.windows(6).any(|window| window == b"[vdso]") {
-                    vdso = Some(parse_addresses(&line[..12], &line[13..25])?);
-                } else if line.windows(6).any(|window| window == b"[vvar]") {
-                    vvar = Some(parse_addresses(&line[..12], &line[13..25])?);
+                let valid = &line[..line_idx];
+                if ends_with_tag(valid, b"[vdso]") {
+                    let (start, end) = parse_start_end(valid)?;
+                    vdso = Some((start as *mut libc::c_void, end - start));
+                } else if ends_with_tag(valid, b"[vvar]") {
+                    let (start, end) = parse_start_end(valid)?;
+                    vvar = Some((start as *mut libc::c_void, end - start));
                 }
                 line_idx = 0; // Reset for the next line
             } else {
@@ -133,22 +375,81 @@ fn find_mapping_addresses() -> Result<
         }
     }
 
-    unsafe { close(fd) };
+    unsafe { sys::close(fd) };
     Ok((vvar, vdso))
 }
 
-fn parse_addresses(
-    start_addr: &[u8],
-    end_addr: &[u8],
-) -> Result<(*mut libc::c_void, libc::size_t), Error> {
-    let start = parse_hex_address(start_addr)?;
-    let end = parse_hex_address(end_addr)?;
+/// Returns true if `line` (the bytes actually written for the current record,
+/// not the whole scratch buffer) ends with `tag`.
+#[cfg(all(target_os = "linux", not(feature = "std")))]
+fn ends_with_tag(line: &[u8], tag: &[u8]) -> bool {
+    line.len() >= tag.len() && &line[line.len() - tag.len()..] == tag
+}
+
+/// Splits a `/proc/self/maps` record's leading `<start>-<end>` field and parses
+/// both addresses. The fields are hex and may be any width (32-bit targets
+/// produce 8 digits, 5-level paging on x86-64 can produce up to 14), so this
+/// locates the `-` and the first whitespace rather than assuming fixed column
+/// offsets.
+fn parse_start_end(line: &[u8]) -> Result<(usize, usize), Error> {
+    let dash = line
+        .iter()
+        .position(|&b| b == b'-')
+        .ok_or(Error::InvalidFormat("missing '-' in maps record"))?;
+    let space = line[dash..]
+        .iter()
+        .position(|&b| b == b' ')
+        .map(|i| i + dash)
+        .ok_or(Error::InvalidFormat("missing whitespace in maps record"))?;
+
+    let start = parse_hex_address(&line[..dash])?;
+    let end = parse_hex_address(&line[dash + 1..space])?;
+    Ok((start, end))
+}
+
+/// Parses one `/proc/self/maps` record of the form
+/// `<start>-<end> <perms> <offset> <dev> <inode> [pathname]` into a
+/// [`MappingRecord`].
+#[cfg(feature = "std")]
+fn parse_mapping_line(line: &[u8]) -> Result<MappingRecord, Error> {
+    let (start, end) = parse_start_end(line)?;
+    let space = line
+        .iter()
+        .position(|&b| b == b' ')
+        .ok_or(Error::InvalidFormat("missing whitespace in maps record"))?;
+
+    let rest = &line[space + 1..];
+    let mut fields = rest.split(|&b| b == b' ').filter(|f| !f.is_empty());
+    let mut perms = [b'-'; 4];
+    if let Some(p) = fields.next() {
+        for (slot, &b) in perms.iter_mut().zip(p) {
+            *slot = b;
+        }
+    }
+    // offset, dev, inode
+    fields.next();
+    fields.next();
+    fields.next();
+    // The pathname may itself contain spaces, so take everything from its
+    // first byte to the end of the record rather than just the first token.
+    let name = fields.next().map(|n| {
+        let offset = n.as_ptr() as usize - line.as_ptr() as usize;
+        std::string::String::from_utf8_lossy(&line[offset..]).into_owned()
+    });
 
-    Ok((start as *mut libc::c_void, end - start))
+    Ok(MappingRecord {
+        start: start as *mut libc::c_void,
+        end: end as *mut libc::c_void,
+        perms,
+        name,
+    })
 }
 
 fn parse_hex_address(addr: &[u8]) -> Result<usize, Error> {
-    let mut num = 0;
+    if addr.is_empty() {
+        return Err(Error::InvalidFormat("empty hexadecimal address"));
+    }
+    let mut num: usize = 0;
     for &byte in addr {
         num = num * 16
             + match byte {
@@ -164,7 +465,7 @@ fn parse_hex_address(addr: &[u8]) -> Result<usize, Error> {
 #[cfg(target_os = "linux")]
 fn allocate_guard_page(address: *mut c_void, size: size_t) -> Result<(), Error> {
     let result = unsafe {
-        mmap(
+        sys::mmap(
             address,
             size,
             PROT_NONE,
@@ -174,8 +475,15 @@ fn allocate_guard_page(address: *mut c_void, size: size_t) -> Result<(), Error>
         )
     };
 
-    if result == libc::MAP_FAILED {
-        Err(Error::IoError("mmap", result as c_int))
+    // libc's mmap signals failure with `MAP_FAILED` (all bits set, i.e. -1);
+    // the raw-syscall backend instead returns a small negative `-errno`. Both
+    // fall in -4095..=-1 when read as a signed value, so check that range
+    // rather than comparing against `MAP_FAILED` specifically. A plain sign
+    // check is not enough: on 32-bit targets a valid mapping address can have
+    // its top bit set.
+    let ret = result as isize;
+    if (-4095..0).contains(&ret) {
+        Err(Error::IoError("mmap", ret as c_int))
     } else {
         Ok(())
     }
@@ -226,3 +534,387 @@ pub fn remove_timer_mappings() -> Result<(), Error> {
 pub fn replace_timer_mappings() -> Result<(), Error> {
     Ok(())
 }
+
+/// Blocks the current thread from reading the hardware timestamp counter
+/// directly (`rdtsc`/`rdtscp`), which would otherwise let code in this
+/// process measure time even after the vDSO has been unmapped.
+///
+/// This sets `PR_TSC_SIGSEGV` via `prctl(PR_SET_TSC, ...)`, so any `rdtsc`/`rdtscp`
+/// executed afterwards raises `SIGSEGV` instead of returning a cycle count.
+/// The TSC flag is per-thread, not per-process: callers must invoke this on
+/// every thread they want hardened.
+#[cfg(target_os = "linux")]
+pub fn disable_tsc() -> Result<(), Error> {
+    let result = unsafe { prctl(PR_SET_TSC, PR_TSC_SIGSEGV, 0, 0, 0) };
+    if result == 0 {
+        Ok(())
+    } else {
+        let errno = unsafe { *__errno_location() };
+        Err(Error::IoError("prctl", errno))
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn disable_tsc() -> Result<(), Error> {
+    Ok(())
+}
+
+/// The `AUDIT_ARCH_*` value the seccomp filter checks `struct seccomp_data.arch`
+/// against, so the filter rejects syscalls made from an unexpected ABI (e.g. a
+/// 32-bit compat syscall on a 64-bit process).
+#[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+const AUDIT_ARCH: u32 = 0x8000_0000 | 0x4000_0000 | 0x3e; // __AUDIT_ARCH_64BIT | __AUDIT_ARCH_LE | EM_X86_64
+#[cfg(all(target_os = "linux", target_arch = "aarch64"))]
+const AUDIT_ARCH: u32 = 0x8000_0000 | 0x4000_0000 | 0xb7; // __AUDIT_ARCH_64BIT | __AUDIT_ARCH_LE | EM_AARCH64
+#[cfg(all(target_os = "linux", target_arch = "x86"))]
+const AUDIT_ARCH: u32 = 0x4000_0000 | 0x03; // __AUDIT_ARCH_LE | EM_386
+
+/// Offsets into `struct seccomp_data`, per `linux/seccomp.h`:
+/// `{ int nr; __u32 arch; __u64 instruction_pointer; __u64 args[6]; }`.
+#[cfg(target_os = "linux")]
+const SECCOMP_DATA_NR_OFFSET: u32 = 0;
+#[cfg(target_os = "linux")]
+const SECCOMP_DATA_ARCH_OFFSET: u32 = 4;
+
+/// Which `SECCOMP_RET_*` action the installed filter takes when a blocked
+/// timing syscall is attempted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeccompAction {
+    /// Fail the syscall with `EPERM` (`SECCOMP_RET_ERRNO`).
+    Errno,
+    /// Raise `SIGSYS` in the calling thread (`SECCOMP_RET_TRAP`).
+    Trap,
+    /// Kill the process immediately (`SECCOMP_RET_KILL_PROCESS`).
+    KillProcess,
+}
+
+#[cfg(target_os = "linux")]
+const fn bpf_stmt(code: u16, k: u32) -> sock_filter {
+    sock_filter {
+        code,
+        jt: 0,
+        jf: 0,
+        k,
+    }
+}
+
+#[cfg(target_os = "linux")]
+const fn bpf_jump(code: u16, k: u32, jt: u8, jf: u8) -> sock_filter {
+    sock_filter { code, jt, jf, k }
+}
+
+/// The number of syscalls [`blocked_syscall_numbers`] returns. `clock_gettime64`
+/// is the 32-bit/time32-arch variant of `clock_gettime` — 64-bit arches have no
+/// separate `clock_gettime64` syscall number (`libc` doesn't define
+/// `SYS_clock_gettime64` for them at all), so it's only counted on arches where
+/// it exists; on 64-bit arches `clock_gettime` alone covers it.
+#[cfg(all(target_os = "linux", target_pointer_width = "32"))]
+const BLOCKED_SYSCALL_COUNT: usize = 6;
+#[cfg(all(target_os = "linux", not(target_pointer_width = "32")))]
+const BLOCKED_SYSCALL_COUNT: usize = 5;
+
+/// The timing syscalls this filter denies. `clock_nanosleep` is the one
+/// "optional" entry called out by the request: it doesn't read the clock
+/// directly, but it does let a caller infer elapsed time by sleeping and
+/// observing wall-clock side effects, so it's included alongside the rest.
+#[cfg(all(target_os = "linux", target_pointer_width = "32"))]
+fn blocked_syscall_numbers() -> [i64; BLOCKED_SYSCALL_COUNT] {
+    [
+        SYS_clock_gettime,
+        SYS_clock_gettime64,
+        SYS_gettimeofday,
+        SYS_time,
+        SYS_clock_getres,
+        SYS_clock_nanosleep,
+    ]
+}
+
+#[cfg(all(target_os = "linux", not(target_pointer_width = "32")))]
+fn blocked_syscall_numbers() -> [i64; BLOCKED_SYSCALL_COUNT] {
+    [
+        SYS_clock_gettime,
+        SYS_gettimeofday,
+        SYS_time,
+        SYS_clock_getres,
+        SYS_clock_nanosleep,
+    ]
+}
+
+/// Installs a seccomp-BPF filter that denies the syscalls glibc (or a direct
+/// caller) would otherwise fall back to once the vDSO's fast path is gone:
+/// `clock_gettime`, `gettimeofday`, `time`, `clock_getres`, and `clock_nanosleep`.
+/// Unmapping the vDSO alone only removes the fast path; the real syscalls still
+/// work, so the timing channel isn't actually closed without this.
+///
+/// `action` selects what happens when a blocked syscall is attempted; see
+/// [`SeccompAction`]. This sets `PR_SET_NO_NEW_PRIVS` first, which is required
+/// for an unprivileged process to install a seccomp filter.
+#[cfg(target_os = "linux")]
+pub fn block_timing_syscalls(action: SeccompAction) -> Result<(), Error> {
+    let result = unsafe { prctl(PR_SET_NO_NEW_PRIVS, 1, 0, 0, 0) };
+    if result != 0 {
+        let errno = unsafe { *__errno_location() };
+        return Err(Error::IoError("prctl(PR_SET_NO_NEW_PRIVS)", errno));
+    }
+
+    let ret_action: u32 = match action {
+        SeccompAction::Errno => SECCOMP_RET_ERRNO | (libc::EPERM as u32 & SECCOMP_RET_DATA),
+        SeccompAction::Trap => SECCOMP_RET_TRAP,
+        SeccompAction::KillProcess => SECCOMP_RET_KILL_PROCESS,
+    };
+
+    let blocked = blocked_syscall_numbers();
+    // arch check (3 insns: load, jeq, kill) + load-syscall-nr insn (1) + 2 insns
+    // per blocked syscall + 1 allow insn
+    let mut filter = [bpf_stmt(0, 0); 3 + 1 + BLOCKED_SYSCALL_COUNT * 2 + 1];
+    let mut i = 0;
+
+    filter[i] = bpf_stmt(
+        (BPF_LD | BPF_W | BPF_ABS) as u16,
+        SECCOMP_DATA_ARCH_OFFSET,
+    );
+    i += 1;
+    // If the arch doesn't match, kill the process outright: a mismatched arch
+    // means a 32-bit compat syscall is being used to dodge this filter.
+    filter[i] = bpf_jump((BPF_JMP | BPF_JEQ | BPF_K) as u16, AUDIT_ARCH, 1, 0);
+    i += 1;
+    filter[i] = bpf_stmt((BPF_RET | BPF_K) as u16, SECCOMP_RET_KILL_PROCESS);
+    i += 1;
+
+    filter[i] = bpf_stmt((BPF_LD | BPF_W | BPF_ABS) as u16, SECCOMP_DATA_NR_OFFSET);
+    i += 1;
+
+    for &nr in blocked.iter() {
+        filter[i] = bpf_jump((BPF_JMP | BPF_JEQ | BPF_K) as u16, nr as u32, 0, 1);
+        i += 1;
+        filter[i] = bpf_stmt((BPF_RET | BPF_K) as u16, ret_action);
+        i += 1;
+    }
+
+    filter[i] = bpf_stmt((BPF_RET | BPF_K) as u16, SECCOMP_RET_ALLOW);
+    i += 1;
+
+    let prog = sock_fprog {
+        len: i as u16,
+        filter: filter.as_mut_ptr(),
+    };
+
+    let result = unsafe {
+        prctl(
+            PR_SET_SECCOMP,
+            SECCOMP_MODE_FILTER,
+            &prog as *const sock_fprog,
+            0,
+            0,
+        )
+    };
+    if result == 0 {
+        Ok(())
+    } else {
+        let errno = unsafe { *__errno_location() };
+        Err(Error::IoError("prctl(PR_SET_SECCOMP)", errno))
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn block_timing_syscalls(_action: SeccompAction) -> Result<(), Error> {
+    Ok(())
+}
+
+/// One mitigation this crate can apply, for use with [`TimerMitigation`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg(feature = "std")]
+pub enum Strategy {
+    /// Unmap the vdso/vvar mappings outright (`remove_timer_mappings`).
+    Unmap,
+    /// Replace the vdso/vvar mappings with `PROT_NONE` guard pages (`replace_timer_mappings`).
+    GuardPage,
+    /// Block direct `rdtsc`/`rdtscp` reads on the current thread (`disable_tsc`).
+    DisableTsc,
+    /// Install a seccomp-BPF filter denying the timing syscalls (`block_timing_syscalls`).
+    SeccompBlock(SeccompAction),
+}
+
+/// What happened when a [`Strategy`] was applied.
+#[derive(Debug)]
+#[cfg(feature = "std")]
+pub enum StepOutcome {
+    /// The strategy completed successfully.
+    Applied,
+    /// The strategy did not need to run, e.g. the vdso/vvar mappings were
+    /// already absent.
+    Skipped(&'static str),
+    /// The strategy ran but failed.
+    Failed(Error),
+}
+
+/// A mapping found while applying a [`TimerMitigation`], reported so callers
+/// can see what this crate observed even if a later step failed.
+#[derive(Debug)]
+#[cfg(feature = "std")]
+pub struct MappingInfo {
+    pub name: &'static str,
+    pub address: *mut libc::c_void,
+    pub size: libc::size_t,
+}
+
+/// The result of [`TimerMitigation::apply`]: the mappings this crate found,
+/// and the outcome of every configured strategy, in the order they were
+/// configured.
+#[derive(Debug, Default)]
+#[cfg(feature = "std")]
+pub struct MitigationReport {
+    pub mappings: std::vec::Vec<MappingInfo>,
+    pub steps: std::vec::Vec<(Strategy, StepOutcome)>,
+    /// Set if reading `/proc/self/maps` to locate the vdso/vvar mappings
+    /// failed outright. When this is `Some`, an empty `mappings` means the
+    /// lookup failed, not that the mappings are genuinely absent — check this
+    /// field before concluding they were already gone.
+    pub mapping_lookup_error: Option<Error>,
+}
+
+/// Builder that composes the strategies in this crate (unmapping, guard pages,
+/// TSC blocking, seccomp filtering) into a single mitigation pass, and reports
+/// what it actually did.
+///
+/// Strategies run in the order they were added, so callers control whether
+/// e.g. seccomp/TSC hardening is installed before or after the vdso/vvar
+/// mappings are torn down. A failure in one strategy does not stop the rest
+/// from running; check [`MitigationReport::steps`] for what succeeded.
+#[derive(Debug, Default)]
+#[cfg(feature = "std")]
+pub struct TimerMitigation {
+    strategies: std::vec::Vec<Strategy>,
+}
+
+#[cfg(feature = "std")]
+impl TimerMitigation {
+    pub fn new() -> Self {
+        Self {
+            strategies: std::vec::Vec::new(),
+        }
+    }
+
+    /// Unmap the vdso/vvar mappings outright.
+    pub fn unmap(mut self) -> Self {
+        self.strategies.push(Strategy::Unmap);
+        self
+    }
+
+    /// Replace the vdso/vvar mappings with `PROT_NONE` guard pages.
+    pub fn guard_page(mut self) -> Self {
+        self.strategies.push(Strategy::GuardPage);
+        self
+    }
+
+    /// Block direct `rdtsc`/`rdtscp` reads on the current thread.
+    pub fn disable_tsc(mut self) -> Self {
+        self.strategies.push(Strategy::DisableTsc);
+        self
+    }
+
+    /// Install a seccomp-BPF filter denying the timing syscalls.
+    pub fn seccomp_block(mut self, action: SeccompAction) -> Self {
+        self.strategies.push(Strategy::SeccompBlock(action));
+        self
+    }
+
+    /// Runs every configured strategy in order and returns a report of what
+    /// was found and what happened.
+    #[cfg(target_os = "linux")]
+    pub fn apply(self) -> MitigationReport {
+        let mut report = MitigationReport::default();
+
+        let lookup_failed;
+        let (vvar, vdso) = match find_mapping_addresses() {
+            Ok(mappings) => {
+                lookup_failed = false;
+                mappings
+            }
+            Err(e) => {
+                lookup_failed = true;
+                report.mapping_lookup_error = Some(e);
+                (None, None)
+            }
+        };
+        if let Some((address, size)) = vdso {
+            report.mappings.push(MappingInfo {
+                name: "[vdso]",
+                address,
+                size,
+            });
+        }
+        if let Some((address, size)) = vvar {
+            report.mappings.push(MappingInfo {
+                name: "[vvar]",
+                address,
+                size,
+            });
+        }
+
+        for strategy in self.strategies {
+            let outcome = match strategy {
+                Strategy::Unmap if lookup_failed => StepOutcome::Failed(
+                    Error::InvalidFormat("could not read /proc/self/maps to locate vdso/vvar"),
+                ),
+                Strategy::Unmap => apply_unmap(vdso, vvar),
+                Strategy::GuardPage if lookup_failed => StepOutcome::Failed(
+                    Error::InvalidFormat("could not read /proc/self/maps to locate vdso/vvar"),
+                ),
+                Strategy::GuardPage => apply_guard_page(vdso, vvar),
+                Strategy::DisableTsc => match disable_tsc() {
+                    Ok(()) => StepOutcome::Applied,
+                    Err(e) => StepOutcome::Failed(e),
+                },
+                Strategy::SeccompBlock(action) => match block_timing_syscalls(action) {
+                    Ok(()) => StepOutcome::Applied,
+                    Err(e) => StepOutcome::Failed(e),
+                },
+            };
+            report.steps.push((strategy, outcome));
+        }
+
+        report
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub fn apply(self) -> MitigationReport {
+        let mut report = MitigationReport::default();
+        for strategy in self.strategies {
+            report.steps.push((
+                strategy,
+                StepOutcome::Skipped("this platform has no timer mitigations to apply"),
+            ));
+        }
+        report
+    }
+}
+
+#[cfg(all(target_os = "linux", feature = "std"))]
+fn apply_unmap(vdso: MappingPair, vvar: MappingPair) -> StepOutcome {
+    if vdso.is_none() && vvar.is_none() {
+        return StepOutcome::Skipped("vdso and vvar mappings already absent");
+    }
+    for (address, size) in vdso.into_iter().chain(vvar) {
+        if let Err(e) = unsafe { unmap_region(address, size) } {
+            return StepOutcome::Failed(e);
+        }
+    }
+    StepOutcome::Applied
+}
+
+#[cfg(all(target_os = "linux", feature = "std"))]
+fn apply_guard_page(vdso: MappingPair, vvar: MappingPair) -> StepOutcome {
+    if vdso.is_none() && vvar.is_none() {
+        return StepOutcome::Skipped("vdso and vvar mappings already absent");
+    }
+    for (address, size) in vdso.into_iter().chain(vvar) {
+        if let Err(e) = unsafe { unmap_region(address, size) } {
+            return StepOutcome::Failed(e);
+        }
+        if let Err(e) = allocate_guard_page(address, size) {
+            return StepOutcome::Failed(e);
+        }
+    }
+    StepOutcome::Applied
+}